@@ -1,14 +1,38 @@
 use common_utils::errors::{ApiModelsError, CustomResult};
 use error_stack::{IntoReport, ResultExt};
-use serde::{de::Visitor, Deserialize, Deserializer};
+use num_rational::Ratio;
+use num_traits::ToPrimitive;
+use serde::{de::Visitor, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use utoipa::ToSchema;
 
-#[derive(Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
+/// Strategy used to round the result of [`Percentage::apply_to`] to a whole minor currency
+/// unit, once the exact rational amount has been computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingStrategy {
+    /// Round half away from zero (e.g. 0.5 -> 1)
+    HalfUp,
+    /// Round half to the nearest even integer (a.k.a. banker's rounding), avoiding the upward
+    /// bias that `HalfUp` accumulates over many transactions
+    HalfEven,
+    /// Always round towards zero, discarding the remainder
+    Floor,
+}
+
+#[derive(Clone, Debug, PartialEq, ToSchema)]
 pub struct Percentage<const PRECISION: u8> {
-    // this value will range from 0 to 100, decimal length defined by precision macro
+    // stored as an exact `numerator / denominator` fraction, reduced to lowest terms, instead of
+    // an `f32` so that repeated fee/surcharge/split calculations never accumulate rounding error
     /// Percentage value ranging between 0 and 100
-    #[schema(example = 2.5)]
-    percentage: f32,
+    #[schema(value_type = f32, example = 2.5)]
+    percentage: Ratio<i64>,
+}
+
+impl<const PRECISION: u8> Default for Percentage<PRECISION> {
+    fn default() -> Self {
+        Self {
+            percentage: Ratio::new(0, 1),
+        }
+    }
 }
 
 fn get_invalid_percentage_error_message(precision: u8) -> String {
@@ -22,19 +46,93 @@ impl<const PRECISION: u8> Percentage<PRECISION> {
     pub fn from_string(value: String) -> CustomResult<Self, ApiModelsError> {
         if Self::is_valid_string_value(&value)? {
             Ok(Self {
-                percentage: value
-                    .parse()
-                    .into_report()
-                    .change_context(ApiModelsError::InvalidPercentageValue)?,
+                percentage: Self::parse_exact(&value)?,
             })
         } else {
             Err(ApiModelsError::InvalidPercentageValue.into())
                 .attach_printable(get_invalid_percentage_error_message(PRECISION))
         }
     }
+
+    /// Parses a decimal string such as "2.5" into an exact fraction (numerator 25, denominator
+    /// 10, reduced to 5/2) rather than going through a lossy `str -> f32` conversion.
+    fn parse_exact(value: &str) -> CustomResult<Ratio<i64>, ApiModelsError> {
+        let (integer_part, decimal_part) = value.split_once('.').unwrap_or((value, ""));
+
+        let denominator = 10i64.pow(decimal_part.len() as u32);
+
+        let integer_value: i64 = if integer_part.is_empty() {
+            0
+        } else {
+            integer_part
+                .parse()
+                .into_report()
+                .change_context(ApiModelsError::InvalidPercentageValue)?
+        };
+        let decimal_value: i64 = if decimal_part.is_empty() {
+            0
+        } else {
+            decimal_part
+                .parse()
+                .into_report()
+                .change_context(ApiModelsError::InvalidPercentageValue)?
+        };
+
+        Ok(Ratio::new(integer_value * denominator + decimal_value, denominator))
+    }
+
+    /// Returns an `f32` approximation of the stored percentage, suitable for display purposes
+    /// only. Use [`Percentage::apply_to`] for money calculations, which stays exact throughout.
     pub fn get_percentage(&self) -> f32 {
-        self.percentage
+        self.percentage.to_f32().unwrap_or_default()
+    }
+
+    /// Applies this percentage to `amount_minor_units` (`amount * pct / 100`), keeping the
+    /// computation as an exact rational and only rounding at the very end, per `rounding`.
+    pub fn apply_to(&self, amount_minor_units: i64, rounding: RoundingStrategy) -> i64 {
+        let exact =
+            Ratio::from_integer(amount_minor_units) * self.percentage / Ratio::from_integer(100);
+        Self::round_ratio(exact, rounding)
+    }
+
+    // `value.floor()` rounds towards negative infinity, which diverges from the documented
+    // "towards zero" / "away from zero" semantics below once `value` is negative (e.g. a -0.5
+    // tie must round to -1 under `HalfUp`, not 0). Truncate instead, and step away from zero by
+    // sign when a strategy needs to round beyond the truncated value.
+    fn round_ratio(value: Ratio<i64>, rounding: RoundingStrategy) -> i64 {
+        let truncated = value.trunc().to_integer();
+        let remainder = value - Ratio::from_integer(truncated);
+        let is_negative = value < Ratio::from_integer(0);
+        let fract = if is_negative { -remainder } else { remainder };
+        let away_from_zero = if is_negative {
+            truncated - 1
+        } else {
+            truncated + 1
+        };
+
+        match rounding {
+            RoundingStrategy::Floor => truncated,
+            RoundingStrategy::HalfUp => {
+                if fract >= Ratio::new(1, 2) {
+                    away_from_zero
+                } else {
+                    truncated
+                }
+            }
+            RoundingStrategy::HalfEven => match fract.cmp(&Ratio::new(1, 2)) {
+                std::cmp::Ordering::Less => truncated,
+                std::cmp::Ordering::Greater => away_from_zero,
+                std::cmp::Ordering::Equal => {
+                    if truncated % 2 == 0 {
+                        truncated
+                    } else {
+                        away_from_zero
+                    }
+                }
+            },
+        }
     }
+
     fn is_valid_string_value(value: &str) -> CustomResult<bool, ApiModelsError> {
         let float_value = Self::is_valid_float_string(value)?;
         Ok(Self::is_valid_range(float_value) && Self::is_valid_precision_length(value))
@@ -61,6 +159,19 @@ impl<const PRECISION: u8> Percentage<PRECISION> {
     }
 }
 
+// custom serde serialization, keeping the `{ "percentage": <f32> }` wire shape stable even
+// though the value is now stored internally as an exact fraction
+impl<const PRECISION: u8> Serialize for Percentage<PRECISION> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Percentage", 1)?;
+        state.serialize_field("percentage", &self.get_percentage())?;
+        state.end()
+    }
+}
+
 // custom serde deserialization function
 struct PercentageVisitor<const PRECISION: u8> {}
 impl<'de, const PRECISION: u8> Visitor<'de> for PercentageVisitor<PRECISION> {
@@ -106,3 +217,57 @@ impl<'de, const PRECISION: u8> Deserialize<'de> for Percentage<PRECISION> {
         data.deserialize_map(PercentageVisitor::<PRECISION> {})
     }
 }
+
+#[cfg(test)]
+mod percentage_tests {
+    use super::*;
+
+    #[test]
+    fn parse_exact_reduces_to_lowest_terms() {
+        let ratio = Percentage::<2>::parse_exact("2.5").unwrap();
+        assert_eq!(ratio, Ratio::new(5, 2));
+    }
+
+    #[test]
+    fn apply_to_half_up_rounds_away_from_zero() {
+        let half_percent = Percentage::<2>::from_string("0.5".to_string()).unwrap();
+        assert_eq!(half_percent.apply_to(100, RoundingStrategy::HalfUp), 1);
+        assert_eq!(half_percent.apply_to(300, RoundingStrategy::HalfUp), 2);
+    }
+
+    #[test]
+    fn apply_to_floor_always_truncates() {
+        let half_percent = Percentage::<2>::from_string("0.5".to_string()).unwrap();
+        assert_eq!(half_percent.apply_to(100, RoundingStrategy::Floor), 0);
+        assert_eq!(half_percent.apply_to(300, RoundingStrategy::Floor), 1);
+    }
+
+    #[test]
+    fn apply_to_half_even_ties_to_nearest_even_floor() {
+        let half_percent = Percentage::<2>::from_string("0.5".to_string()).unwrap();
+        // 100 * 0.5% = 0.5, floor 0 is even, so the tie stays at 0
+        assert_eq!(half_percent.apply_to(100, RoundingStrategy::HalfEven), 0);
+        // 300 * 0.5% = 1.5, floor 1 is odd, so the tie rounds up to 2
+        assert_eq!(half_percent.apply_to(300, RoundingStrategy::HalfEven), 2);
+    }
+
+    #[test]
+    fn from_string_apply_to_round_trip_stays_exact() {
+        let percentage = Percentage::<2>::from_string("2.5".to_string()).unwrap();
+        assert_eq!(percentage.apply_to(1000, RoundingStrategy::Floor), 25);
+    }
+
+    #[test]
+    fn apply_to_negative_amount_floor_truncates_towards_zero() {
+        let half_percent = Percentage::<2>::from_string("0.5".to_string()).unwrap();
+        // -100 * 0.5% = -0.5, which truncates towards zero, not floors towards -infinity
+        assert_eq!(half_percent.apply_to(-100, RoundingStrategy::Floor), 0);
+    }
+
+    #[test]
+    fn apply_to_negative_amount_half_up_rounds_away_from_zero() {
+        let half_percent = Percentage::<2>::from_string("0.5".to_string()).unwrap();
+        // -100 * 0.5% = -0.5, a tie that rounds away from zero to -1
+        assert_eq!(half_percent.apply_to(-100, RoundingStrategy::HalfUp), -1);
+    }
+}
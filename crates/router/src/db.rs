@@ -125,13 +125,7 @@ pub async fn get_and_deserialize_key<T>(
 where
     T: serde::de::DeserializeOwned,
 {
-    use common_utils::ext_traits::ByteSliceExt;
-    use error_stack::ResultExt;
-
-    let bytes = db.get_key(key).await?;
-    bytes
-        .parse_struct(type_name)
-        .change_context(redis_interface::errors::RedisError::JsonDeserializationFailed)
+    cache::get_or_populate_redis(db, key, type_name).await
 }
 
 pub enum KvOperation<'a, S: serde::Serialize + Debug> {
@@ -139,6 +133,8 @@ pub enum KvOperation<'a, S: serde::Serialize + Debug> {
     SetNx(S),
     HSetNx(&'a str, S),
     Get(&'a str),
+    /// Fetches several hash fields of `key` in one round-trip.
+    MGet(&'a [&'a str]),
     Scan(&'a str),
 }
 
@@ -146,6 +142,7 @@ pub enum KvOperation<'a, S: serde::Serialize + Debug> {
 #[error(RedisError(UnknownResult))]
 pub enum KvResult<T: de::DeserializeOwned> {
     Get(T),
+    MGet(Vec<T>),
     Hset(()),
     SetNx(redis_interface::SetnxReply),
     HSetNx(redis_interface::HsetnxReply),
@@ -179,6 +176,12 @@ where
                 .await?;
             Ok(KvResult::Get(result))
         }
+        KvOperation::MGet(fields) => {
+            let result = redis_conn
+                .get_hash_fields_and_deserialize(key, fields, type_name)
+                .await?;
+            Ok(KvResult::MGet(result))
+        }
         KvOperation::Scan(pattern) => {
             let result: Vec<T> = redis_conn.hscan_and_deserialize(key, pattern, None).await?;
             Ok(KvResult::Scan(result))
@@ -198,4 +201,47 @@ where
     }
 }
 
+/// Runs `ops` against `key` inside a single Redis pipeline/`MULTI` instead of one round-trip per
+/// operation. Results are returned in the same order as `ops`.
+pub async fn kv_wrapper_batch<'a, T, S>(
+    store: &Store,
+    ops: &[KvOperation<'a, S>],
+    key: impl AsRef<str>,
+) -> CustomResult<Vec<KvResult<T>>, RedisError>
+where
+    T: de::DeserializeOwned,
+    S: serde::Serialize + Debug,
+{
+    let redis_conn = store.get_redis_conn()?;
+
+    let key = key.as_ref();
+    let type_name = std::any::type_name::<T>();
+
+    let mut pipeline = redis_conn.pipeline();
+    for op in ops {
+        match op {
+            KvOperation::Hset((field, value)) => {
+                pipeline.queue_hset(key, (*field, value.clone()), Some(consts::KV_TTL));
+            }
+            KvOperation::Get(field) => {
+                pipeline.queue_get(key, field, type_name);
+            }
+            KvOperation::MGet(fields) => {
+                pipeline.queue_mget(key, fields, type_name);
+            }
+            KvOperation::Scan(pattern) => {
+                pipeline.queue_hscan(key, pattern, type_name);
+            }
+            KvOperation::HSetNx(field, value) => {
+                pipeline.queue_hsetnx(key, field, value, Some(consts::KV_TTL));
+            }
+            KvOperation::SetNx(value) => {
+                pipeline.queue_setnx(key, value, Some(consts::KV_TTL.into()));
+            }
+        }
+    }
+
+    pipeline.execute().await
+}
+
 dyn_clone::clone_trait_object!(StorageInterface);
@@ -0,0 +1,151 @@
+use std::{
+    num::NonZeroUsize,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use lru::LruCache;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use redis_interface::errors::RedisError;
+
+use crate::db::StorageInterface;
+
+// ---- L1: bounded, TTL'd in-process cache in front of the Redis (L2) read-through below ----
+
+const IN_MEMORY_CACHE_CAPACITY: usize = 16_384;
+const IN_MEMORY_CACHE_TTL: Duration = Duration::from_secs(60);
+
+struct CacheEntry {
+    value: Vec<u8>,
+    inserted_at: Instant,
+}
+
+/// Bounded, TTL'd in-process cache sitting in front of [`get_or_populate_redis`]. Stores the same
+/// raw serialized bytes the Redis path returns, so callers deserialize identically either way.
+pub struct InMemoryCache {
+    inner: Mutex<LruCache<String, CacheEntry>>,
+    ttl: Duration,
+}
+
+impl InMemoryCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            inner: Mutex::new(LruCache::new(capacity)),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut cache = self.inner.lock();
+        match cache.get(key) {
+            Some(entry) if entry.inserted_at.elapsed() < self.ttl => Some(entry.value.clone()),
+            Some(_) => {
+                cache.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: String, value: Vec<u8>) {
+        self.inner.lock().put(
+            key,
+            CacheEntry {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    pub fn invalidate(&self, key: &str) {
+        self.inner.lock().pop(key);
+    }
+}
+
+static IN_MEMORY_CACHE: Lazy<Arc<InMemoryCache>> =
+    Lazy::new(|| Arc::new(InMemoryCache::new(IN_MEMORY_CACHE_CAPACITY, IN_MEMORY_CACHE_TTL)));
+
+/// The process-wide L1 cache instance, shared by every caller of [`get_or_populate_redis`].
+pub fn in_memory() -> Arc<InMemoryCache> {
+    IN_MEMORY_CACHE.clone()
+}
+
+pub fn invalidate(key: &str) {
+    IN_MEMORY_CACHE.invalidate(key);
+}
+
+/// Redis (L2) read-through for keys that don't have a dedicated in-process cache, checking the
+/// L1 cache above first and populating it on a miss. Used by `merchant_account`/`configs`/
+/// `business_profile`/`cards_info` via [`crate::db::get_and_deserialize_key`].
+pub async fn get_or_populate_redis<T>(
+    db: &dyn StorageInterface,
+    key: &str,
+    type_name: &'static str,
+) -> CustomResult<T, RedisError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    use common_utils::ext_traits::ByteSliceExt;
+
+    let bytes = match in_memory().get(key) {
+        Some(bytes) => bytes,
+        None => {
+            let bytes = db.get_key(key).await?;
+            in_memory().insert(key.to_string(), bytes.clone());
+            bytes
+        }
+    };
+
+    bytes
+        .parse_struct(type_name)
+        .change_context(RedisError::JsonDeserializationFailed)
+}
+
+#[cfg(test)]
+mod in_memory_cache_tests {
+    use std::thread::sleep;
+
+    use super::*;
+
+    #[test]
+    fn get_returns_none_for_missing_key() {
+        let cache = InMemoryCache::new(2, Duration::from_secs(60));
+        assert_eq!(cache.get("missing"), None);
+    }
+
+    #[test]
+    fn insert_then_get_round_trips() {
+        let cache = InMemoryCache::new(2, Duration::from_secs(60));
+        cache.insert("key".to_string(), b"value".to_vec());
+        assert_eq!(cache.get("key"), Some(b"value".to_vec()));
+    }
+
+    #[test]
+    fn entry_expires_after_ttl() {
+        let cache = InMemoryCache::new(2, Duration::from_millis(10));
+        cache.insert("key".to_string(), b"value".to_vec());
+        sleep(Duration::from_millis(20));
+        assert_eq!(cache.get("key"), None);
+    }
+
+    #[test]
+    fn invalidate_evicts_entry() {
+        let cache = InMemoryCache::new(2, Duration::from_secs(60));
+        cache.insert("key".to_string(), b"value".to_vec());
+        cache.invalidate("key");
+        assert_eq!(cache.get("key"), None);
+    }
+
+    #[test]
+    fn capacity_evicts_least_recently_used_entry() {
+        let cache = InMemoryCache::new(1, Duration::from_secs(60));
+        cache.insert("first".to_string(), b"1".to_vec());
+        cache.insert("second".to_string(), b"2".to_vec());
+        assert_eq!(cache.get("first"), None);
+        assert_eq!(cache.get("second"), Some(b"2".to_vec()));
+    }
+}